@@ -0,0 +1,203 @@
+use std::collections::HashMap;
+
+use clap::ValueEnum;
+
+use crate::epithet_config::EpithetConfig;
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+#[value(rename_all = "lower")]
+pub enum Shell {
+    Bash,
+    Zsh,
+    Fish,
+}
+
+pub fn completion_script(shell: Shell, config: &EpithetConfig) -> String {
+    let aliases: Vec<&str> = config
+        .aliases
+        .as_ref()
+        .map(|aliases| aliases.keys().map(|key| key.as_str()).collect())
+        .unwrap_or_default();
+
+    match shell {
+        Shell::Bash => bash_script(&aliases),
+        Shell::Zsh => zsh_script(&aliases),
+        Shell::Fish => fish_script(&aliases),
+    }
+}
+
+fn bash_script(aliases: &[&str]) -> String {
+    format!(
+        r#"_epithet_complete() {{
+    local cur alias
+    cur="${{COMP_WORDS[COMP_CWORD]}}"
+    alias="${{COMP_WORDS[0]}}"
+    COMPREPLY=( $(compgen -W "$(epithet complete "$alias" "$cur" "$COMP_CWORD")" -- "$cur") )
+}}
+
+complete -F _epithet_complete {aliases}
+"#,
+        aliases = aliases.join(" ")
+    )
+}
+
+fn zsh_script(aliases: &[&str]) -> String {
+    format!(
+        r#"#compdef {aliases}
+
+_epithet_complete() {{
+    local cur words
+    cur="${{words[CURRENT]}}"
+    reply=( $(epithet complete "${{words[1]}}" "$cur" $((CURRENT - 1))) )
+    compadd -a reply
+}}
+
+compdef _epithet_complete {aliases}
+"#,
+        aliases = aliases.join(" ")
+    )
+}
+
+fn fish_script(aliases: &[&str]) -> String {
+    let mut script = String::new();
+    for alias in aliases {
+        script.push_str(&format!(
+            "complete -c {alias} -f -a '(epithet complete {alias} (commandline -ct) (count (commandline -opc)))'\n",
+            alias = alias
+        ));
+    }
+    script
+}
+
+pub fn complete(config: &EpithetConfig, alias: &str, current: &str, position: usize) -> Vec<String> {
+    let Some(aliases) = &config.aliases else {
+        return Vec::new();
+    };
+    let Some(alias_entry) = aliases.get(alias) else {
+        return Vec::new();
+    };
+
+    let global_expansions: HashMap<String, String> =
+        config.global_expansions.clone().unwrap_or_default();
+    let expansion_keys = alias_entry.expansion_keys(&global_expansions);
+
+    let mut candidates = Vec::new();
+
+    if let Some(prefix) = current.strip_prefix('@') {
+        for key in &expansion_keys {
+            if key.starts_with(prefix) {
+                candidates.push(format!("@{}", key));
+            }
+        }
+        return candidates;
+    }
+
+    if position == 1 {
+        if let Some(sub_aliases) = &alias_entry.sub_aliases {
+            for sub_alias in sub_aliases {
+                if sub_alias.name.starts_with(current) {
+                    candidates.push(sub_alias.name.clone());
+                }
+            }
+        }
+    }
+
+    for key in &expansion_keys {
+        let token = format!("@{}", key);
+        if token.starts_with(current) {
+            candidates.push(token);
+        }
+    }
+
+    candidates
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use rstest::rstest;
+
+    use super::*;
+    use crate::epithet_config::{Alias, Execution, Expansion, SubAlias};
+
+    fn sample_config() -> EpithetConfig {
+        let mut aliases = HashMap::new();
+        aliases.insert(
+            "greet".to_string(),
+            Alias {
+                command: Some(Execution::Command("echo {0}".to_string())),
+                sub_aliases: Some(vec![
+                    SubAlias {
+                        name: "loud".to_string(),
+                        execution: Execution::Command("echo LOUD".to_string()),
+                        cwd: None,
+                        env: None,
+                        params: None,
+                    },
+                    SubAlias {
+                        name: "quiet".to_string(),
+                        execution: Execution::Command("echo quiet".to_string()),
+                        cwd: None,
+                        env: None,
+                        params: None,
+                    },
+                ]),
+                expansions: Some(vec![Expansion {
+                    key: "dest".to_string(),
+                    value: "/srv".to_string(),
+                }]),
+                cwd: None,
+                env: None,
+                params: None,
+            },
+        );
+
+        EpithetConfig {
+            global_expansions: None,
+            aliases: Some(aliases),
+        }
+    }
+
+    #[test]
+    fn test_complete_unknown_alias_returns_empty() {
+        let config = sample_config();
+
+        assert!(complete(&config, "missing", "", 1).is_empty());
+    }
+
+    #[rstest]
+    #[case("lo", vec!["loud"])]
+    #[case("", vec!["loud", "quiet", "@dest"])]
+    fn test_complete_matches_sub_alias_prefix(
+        #[case] current: &str,
+        #[case] expected: Vec<&str>,
+    ) {
+        let config = sample_config();
+
+        assert_eq!(complete(&config, "greet", current, 1), expected);
+    }
+
+    #[test]
+    fn test_complete_matches_expansion_key_prefix() {
+        let config = sample_config();
+
+        assert_eq!(complete(&config, "greet", "@de", 1), vec!["@dest"]);
+    }
+
+    #[test]
+    fn test_complete_only_offers_sub_aliases_at_first_position() {
+        let config = sample_config();
+
+        assert_eq!(complete(&config, "greet", "", 2), vec!["@dest"]);
+    }
+
+    #[test]
+    fn test_completion_script_registers_every_alias() {
+        let config = sample_config();
+
+        let script = completion_script(Shell::Bash, &config);
+
+        assert!(script.contains("greet"));
+    }
+}
@@ -4,7 +4,7 @@ use std::{
     fmt::Display,
     fs,
     path::{Path, PathBuf},
-    process::{exit, Command, ExitStatus},
+    process::{exit, Child, Command, ExitStatus, Stdio},
 };
 
 use anyhow::Result;
@@ -35,18 +35,35 @@ impl EpithetConfig {
         Self::read(&config_path)
     }
 
-    fn read(path: &Path) -> Result<Self> {
+    pub fn read(path: &Path) -> Result<Self> {
         let config_contents = fs::read_to_string(path)?;
 
         Ok(toml::from_str(&config_contents)?)
     }
 
-    pub fn lookup_alias(&self, alias: &str, args: &[String]) -> Option<String> {
-        if let Some(alias) = self.find_alias(alias) {
-            return alias.lookup(args);
+    /// Serialize the config back to `path`, writing atomically via a temp file + rename.
+    pub fn write(&self, path: &Path) -> Result<()> {
+        let contents = toml::to_string_pretty(self)?;
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
         }
 
-        None
+        let tmp_path = path.with_extension("toml.tmp");
+        fs::write(&tmp_path, contents)?;
+        fs::rename(&tmp_path, path)?;
+
+        Ok(())
+    }
+
+    pub fn lookup_alias(&self, alias: &str, args: &[String]) -> Result<Option<String>> {
+        if let Some(alias_entry) = self.find_alias(alias) {
+            alias_entry.lookup(args)
+        } else if let Some(suggestion) = self.suggest_alias(alias) {
+            anyhow::bail!("Alias not found: {}. Did you mean '{}'?", alias, suggestion);
+        } else {
+            Ok(None)
+        }
     }
 
     fn find_alias(&self, alias: &str) -> Option<&Alias> {
@@ -60,15 +77,22 @@ impl EpithetConfig {
     }
 
     pub fn execute(&self, alias: &str, args: &[String]) -> Result<()> {
-        if let Some(alias) = self.find_alias(alias) {
+        if let Some(alias_entry) = self.find_alias(alias) {
             let global_expansions = self.global_expansions.clone().unwrap_or_default();
-            alias.execute(args, &global_expansions)?;
+            alias_entry.execute(args, &global_expansions)?;
+        } else if let Some(suggestion) = self.suggest_alias(alias) {
+            anyhow::bail!("Alias not found: {}. Did you mean '{}'?", alias, suggestion);
         } else {
             anyhow::bail!("Alias not found: {}", alias);
         }
 
         Ok(())
     }
+
+    fn suggest_alias(&self, alias: &str) -> Option<String> {
+        let aliases = self.aliases.as_ref()?;
+        suggest_closest(alias, aliases.keys().map(|key| key.as_str()))
+    }
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -77,6 +101,9 @@ pub struct Alias {
     pub command: Option<Execution>,
     pub sub_aliases: Option<Vec<SubAlias>>,
     pub expansions: Option<Vec<Expansion>>,
+    pub cwd: Option<String>,
+    pub env: Option<HashMap<String, String>>,
+    pub params: Option<Vec<String>>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -96,37 +123,79 @@ impl Alias {
                 for sub_alias in sub_aliases {
                     if sub_alias.name == *sub_command {
                         let rest = &args[1..];
-                        return sub_alias
-                            .execution
-                            .execute(rest, &self.get_expansions(global_expansions));
+                        let expansions = self.get_expansions(global_expansions);
+                        let params = sub_alias.params.as_deref();
+                        let env = expand_env(sub_alias.env.as_ref(), rest, &expansions, params)?;
+                        return sub_alias.execution.execute(
+                            rest,
+                            &expansions,
+                            sub_alias.cwd.as_deref(),
+                            env.as_ref(),
+                            params,
+                        );
+                    }
+                }
+
+                if self.command.is_none() {
+                    let names = sub_aliases.iter().map(|s| s.name.as_str());
+                    if let Some(suggestion) = suggest_closest(sub_command, names) {
+                        anyhow::bail!(
+                            "Sub-alias not found: {}. Did you mean '{}'?",
+                            sub_command,
+                            suggestion
+                        );
                     }
                 }
             }
         }
 
         if let Some(command) = &self.command {
-            return command.execute(args, &self.get_expansions(global_expansions));
+            let expansions = self.get_expansions(global_expansions);
+            let params = self.params.as_deref();
+            let env = expand_env(self.env.as_ref(), args, &expansions, params)?;
+            return command.execute(
+                args,
+                &expansions,
+                self.cwd.as_deref(),
+                env.as_ref(),
+                params,
+            );
         }
 
         Ok(())
     }
 
-    pub fn lookup(&self, args: &[String]) -> Option<String> {
+    pub fn lookup(&self, args: &[String]) -> Result<Option<String>> {
         if let Some(sub_command) = args.first() {
             if let Some(sub_aliases) = &self.sub_aliases {
                 for sub_alias in sub_aliases {
                     if sub_alias.name == *sub_command {
-                        return Some(format!("{}", sub_alias.execution));
+                        return Ok(Some(format!("{}", sub_alias.execution)));
+                    }
+                }
+
+                if self.command.is_none() {
+                    let names = sub_aliases.iter().map(|s| s.name.as_str());
+                    if let Some(suggestion) = suggest_closest(sub_command, names) {
+                        anyhow::bail!(
+                            "Sub-alias not found: {}. Did you mean '{}'?",
+                            sub_command,
+                            suggestion
+                        );
                     }
                 }
             }
         }
 
         if let Some(command) = &self.command {
-            return Some(format!("{}", command));
+            return Ok(Some(format!("{}", command)));
         }
 
-        None
+        Ok(None)
+    }
+
+    pub fn expansion_keys(&self, global_expansions: &HashMap<String, String>) -> Vec<String> {
+        self.get_expansions(global_expansions).into_keys().collect()
     }
 
     fn get_expansions(
@@ -145,6 +214,37 @@ impl Alias {
     }
 }
 
+fn levenshtein_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    let mut previous_row: Vec<usize> = (0..=b.len()).collect();
+    let mut current_row = vec![0; b.len() + 1];
+
+    for i in 1..=a.len() {
+        current_row[0] = i;
+        for j in 1..=b.len() {
+            let substitution_cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            current_row[j] = (previous_row[j] + 1)
+                .min(current_row[j - 1] + 1)
+                .min(previous_row[j - 1] + substitution_cost);
+        }
+        std::mem::swap(&mut previous_row, &mut current_row);
+    }
+
+    previous_row[b.len()]
+}
+
+const SUGGESTION_DISTANCE_THRESHOLD: usize = 3;
+
+fn suggest_closest<'a>(name: &str, candidates: impl Iterator<Item = &'a str>) -> Option<String> {
+    candidates
+        .map(|candidate| (candidate, levenshtein_distance(name, candidate)))
+        .filter(|(_, distance)| *distance < SUGGESTION_DISTANCE_THRESHOLD)
+        .min_by_key(|(_, distance)| *distance)
+        .map(|(candidate, _)| candidate.to_string())
+}
+
 fn tokenize_string(string: &str) -> Vec<String> {
     let mut tokens = Vec::new();
     let mut current_token = String::new();
@@ -183,6 +283,10 @@ pub struct SubAlias {
 
     #[serde(flatten)]
     pub execution: Execution,
+
+    pub cwd: Option<String>,
+    pub env: Option<HashMap<String, String>>,
+    pub params: Option<Vec<String>>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -195,11 +299,18 @@ pub enum Execution {
 }
 
 impl Execution {
-    pub fn execute(&self, args: &[String], expansions: &HashMap<String, String>) -> Result<()> {
+    pub fn execute(
+        &self,
+        args: &[String],
+        expansions: &HashMap<String, String>,
+        cwd: Option<&str>,
+        env: Option<&HashMap<String, String>>,
+        params: Option<&[String]>,
+    ) -> Result<()> {
         match self {
             Execution::Command(command) => {
-                let tokens = self.get_arguments(command, args, expansions);
-                let result = execute_command(&tokens)?;
+                let tokens = get_arguments(command, args, expansions, params, true)?;
+                let result = execute_command(&tokens, cwd, env)?;
                 if !result.success() {
                     exit(result.code().unwrap_or(1));
                 }
@@ -207,8 +318,8 @@ impl Execution {
             }
             Execution::And(items) => {
                 for item in items {
-                    let tokens = self.get_arguments(item, args, expansions);
-                    let result = execute_command(&tokens)?;
+                    let tokens = get_arguments(item, args, expansions, params, true)?;
+                    let result = execute_command(&tokens, cwd, env)?;
                     if !result.success() {
                         exit(result.code().unwrap_or(1));
                     }
@@ -218,8 +329,8 @@ impl Execution {
             Execution::Or(items) => {
                 let mut last_result = None;
                 for item in items {
-                    let tokens = self.get_arguments(item, args, expansions);
-                    let result = execute_command(&tokens)?;
+                    let tokens = get_arguments(item, args, expansions, params, true)?;
+                    let result = execute_command(&tokens, cwd, env)?;
                     if result.success() {
                         return Ok(());
                     }
@@ -232,57 +343,117 @@ impl Execution {
                         .unwrap_or(1),
                 );
             }
-            Execution::Pipeline(_items) => todo!(),
+            Execution::Pipeline(items) => {
+                let stages = items
+                    .iter()
+                    .enumerate()
+                    .map(|(i, item)| get_arguments(item, args, expansions, params, i == 0))
+                    .collect::<Result<Vec<_>>>()?;
+                let result = execute_pipeline(stages, cwd, env)?;
+                if !result.success() {
+                    exit(result.code().unwrap_or(1));
+                }
+                Ok(())
+            }
         }
     }
+}
 
-    fn get_arguments(
-        &self,
-        command: &str,
-        arguments: &[String],
-        expansions: &HashMap<String, String>,
-    ) -> Vec<String> {
-        let argument_tokens: Vec<String> = arguments
-            .iter()
-            .flat_map(|arg| {
-                if arg.starts_with("@") {
-                    let key = arg.trim_start_matches("@").to_string();
-                    let value = expansions.get(&key).unwrap_or(arg).to_string();
-                    tokenize_string(&value)
-                } else {
-                    vec![arg.to_string()]
+fn get_arguments(
+    command: &str,
+    arguments: &[String],
+    expansions: &HashMap<String, String>,
+    params: Option<&[String]>,
+    include_leftovers: bool,
+) -> Result<Vec<String>> {
+    let argument_tokens: Vec<String> = arguments
+        .iter()
+        .flat_map(|arg| {
+            if arg.starts_with("@") {
+                let key = arg.trim_start_matches("@").to_string();
+                let value = expansions.get(&key).unwrap_or(arg).to_string();
+                tokenize_string(&value)
+            } else {
+                vec![arg.to_string()]
+            }
+        })
+        .collect();
+
+    expand_command(command, &argument_tokens, params, include_leftovers)
+}
+
+/// Substitute `{0}`/`{name}` placeholders in `command` with values from `arguments`.
+/// Unless `include_leftovers` is false, any argument not consumed by a placeholder is
+/// appended to the end (e.g. so `{0}` consumes the first arg and trailing flags still pass
+/// through) — pipeline stages after the first pass `false` so leftover args aren't
+/// duplicated onto every stage.
+fn expand_command(
+    command: &str,
+    arguments: &[String],
+    params: Option<&[String]>,
+    include_leftovers: bool,
+) -> Result<Vec<String>> {
+    let mut arguments_copy: Vec<Option<String>> =
+        arguments.iter().map(|a| Some(a.to_string())).collect();
+    let command_tokens = tokenize_string(command);
+
+    let mut tokens = Vec::with_capacity(command_tokens.len());
+    for token in command_tokens {
+        if token.starts_with('{') && token.ends_with('}') {
+            let placeholder = &token[1..token.len() - 1];
+
+            if let Ok(position) = placeholder.parse::<usize>() {
+                if position < arguments.len() {
+                    arguments_copy[position] = None;
+                    tokens.push(arguments[position].clone());
+                    continue;
                 }
-            })
-            .collect();
-
-        self.expand_command(command, &argument_tokens)
-    }
-
-    fn expand_command(&self, command: &str, arguments: &[String]) -> Vec<String> {
-        let mut arguments_copy: Vec<Option<String>> =
-            arguments.iter().map(|a| Some(a.to_string())).collect();
-        let command_tokens = tokenize_string(command);
-
-        let mut tokens: Vec<String> = command_tokens
-            .into_iter()
-            .map(|token| {
-                if token.starts_with("{") && token.ends_with("}") {
-                    if let Ok(position) = &token[1..token.len() - 1].parse::<usize>() {
-                        if position < &arguments.len() {
-                            arguments_copy[*position] = None;
-                            return arguments[*position].clone();
-                        }
-                    }
+            } else if let Some(params) = params {
+                let position = params
+                    .iter()
+                    .position(|name| name == placeholder)
+                    .ok_or_else(|| {
+                        anyhow::anyhow!("Unknown named parameter: {{{}}}", placeholder)
+                    })?;
+                if position >= arguments.len() {
+                    anyhow::bail!("Unbound named parameter: {{{}}}", placeholder);
                 }
+                arguments_copy[position] = None;
+                tokens.push(arguments[position].clone());
+                continue;
+            }
+        }
 
-                token.to_string()
-            })
-            .collect();
+        tokens.push(token);
+    }
 
+    if include_leftovers {
         tokens.extend(arguments_copy.into_iter().flatten());
-
-        tokens
     }
+
+    Ok(tokens)
+}
+
+/// Expand `@expansion`/`{0}`/named-param substitution in each env value against `arguments`.
+fn expand_env(
+    env: Option<&HashMap<String, String>>,
+    arguments: &[String],
+    expansions: &HashMap<String, String>,
+    params: Option<&[String]>,
+) -> Result<Option<HashMap<String, String>>> {
+    let Some(env) = env else {
+        return Ok(None);
+    };
+
+    let expanded = env
+        .iter()
+        .map(|(key, value)| {
+            let expanded = get_arguments(value, arguments, expansions, params, true)?.join(" ");
+            Ok((key.clone(), expanded))
+        })
+        .collect::<Result<HashMap<_, _>>>()?;
+
+    Ok(Some(expanded))
 }
 
 impl Display for Execution {
@@ -296,21 +467,182 @@ impl Display for Execution {
     }
 }
 
-fn execute_command(tokens: &[String]) -> Result<ExitStatus> {
+fn apply_cwd_and_env(
+    command: &mut Command,
+    cwd: Option<&str>,
+    env: Option<&HashMap<String, String>>,
+) {
+    if let Some(cwd) = cwd {
+        command.current_dir(shellexpand::tilde(cwd).to_string());
+    }
+    if let Some(env) = env {
+        command.envs(env);
+    }
+}
+
+fn execute_command(
+    tokens: &[String],
+    cwd: Option<&str>,
+    env: Option<&HashMap<String, String>>,
+) -> Result<ExitStatus> {
     let cmd = shellexpand::tilde(tokens.first().expect("No command provided")).to_string();
 
-    Command::new(cmd)
-        .args(&tokens[1..])
+    let mut command = Command::new(cmd);
+    command.args(&tokens[1..]);
+    apply_cwd_and_env(&mut command, cwd, env);
+
+    command
         .status()
         .map_err(|e| anyhow::anyhow!("Failed to execute command: {}", e))
 }
 
+fn execute_pipeline(
+    stages: Vec<Vec<String>>,
+    cwd: Option<&str>,
+    env: Option<&HashMap<String, String>>,
+) -> Result<ExitStatus> {
+    if stages.is_empty() {
+        anyhow::bail!("Pipeline must contain at least one stage");
+    }
+
+    let last = stages.len() - 1;
+    let mut children: Vec<Child> = Vec::with_capacity(stages.len());
+    let mut previous_stdout = None;
+
+    for (i, tokens) in stages.into_iter().enumerate() {
+        let cmd = shellexpand::tilde(tokens.first().expect("No command provided")).to_string();
+
+        let mut command = Command::new(cmd);
+        command.args(&tokens[1..]);
+        apply_cwd_and_env(&mut command, cwd, env);
+        command.stdin(previous_stdout.take().map_or(Stdio::inherit(), Stdio::from));
+        command.stdout(if i == last {
+            Stdio::inherit()
+        } else {
+            Stdio::piped()
+        });
+
+        let mut child = match command.spawn() {
+            Ok(child) => child,
+            Err(e) => {
+                reap_children(children);
+                return Err(anyhow::anyhow!("Failed to execute command: {}", e));
+            }
+        };
+        previous_stdout = child.stdout.take();
+        children.push(child);
+    }
+
+    let mut final_status = None;
+    for mut child in children {
+        let status = child.wait()?;
+        final_status = Some(status);
+    }
+
+    Ok(final_status.expect("Pipeline must contain at least one stage"))
+}
+
+/// Kill and reap already-spawned pipeline stages after a later stage fails to spawn,
+/// so they don't hang writing to a pipe nobody will ever read.
+fn reap_children(children: Vec<Child>) {
+    for mut child in children {
+        let _ = child.kill();
+        let _ = child.wait();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
 
     use super::*;
 
+    #[test]
+    fn test_lookup_alias_returns_suggestion_for_unknown_alias() {
+        let mut aliases = HashMap::new();
+        aliases.insert(
+            "install".to_string(),
+            Alias {
+                command: Some(Execution::Command("echo installed".to_string())),
+                sub_aliases: None,
+                expansions: None,
+                cwd: None,
+                env: None,
+                params: None,
+            },
+        );
+        let config = EpithetConfig {
+            global_expansions: None,
+            aliases: Some(aliases),
+        };
+
+        let err = config.lookup_alias("instal", &[]).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Alias not found: instal. Did you mean 'install'?"
+        );
+    }
+
+    #[test]
+    fn test_alias_lookup_returns_suggestion_for_unknown_sub_alias() {
+        let alias = Alias {
+            command: None,
+            sub_aliases: Some(vec![SubAlias {
+                name: "loud".to_string(),
+                execution: Execution::Command("echo LOUD".to_string()),
+                cwd: None,
+                env: None,
+                params: None,
+            }]),
+            expansions: None,
+            cwd: None,
+            env: None,
+            params: None,
+        };
+
+        let err = alias.lookup(&["loudd".to_string()]).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Sub-alias not found: loudd. Did you mean 'loud'?"
+        );
+    }
+
+    #[test]
+    fn test_write_then_read_round_trips_config() {
+        let path = std::env::temp_dir()
+            .join("epithet-config-test-write-read")
+            .join("epithet.toml");
+        let _ = fs::remove_dir_all(path.parent().unwrap());
+
+        let mut aliases = HashMap::new();
+        aliases.insert(
+            "greet".to_string(),
+            Alias {
+                command: Some(Execution::Command("echo {0}".to_string())),
+                sub_aliases: None,
+                expansions: None,
+                cwd: Some("/tmp".to_string()),
+                env: None,
+                params: None,
+            },
+        );
+        let config = EpithetConfig {
+            global_expansions: None,
+            aliases: Some(aliases),
+        };
+
+        config.write(&path).unwrap();
+
+        assert!(!path.with_extension("toml.tmp").exists());
+
+        let read_back = EpithetConfig::read(&path).unwrap();
+        let alias = &read_back.aliases.unwrap()["greet"];
+        assert!(matches!(&alias.command, Some(Execution::Command(cmd)) if cmd == "echo {0}"));
+        assert_eq!(alias.cwd.as_deref(), Some("/tmp"));
+    }
+
     #[rstest]
     #[case("echo \"Hello, world!\"", vec!["echo", "Hello, world!"])]
     #[case("echo Hello, world!", vec!["echo", "Hello,", "world!"])]
@@ -318,4 +650,172 @@ mod tests {
     fn test_tokenize_string(#[case] input: &str, #[case] expected: Vec<&str>) {
         assert_eq!(tokenize_string(input), expected);
     }
+
+    #[rstest]
+    #[case("kitten", "sitting", 3)]
+    #[case("build", "build", 0)]
+    #[case("", "abc", 3)]
+    #[case("instal", "install", 1)]
+    fn test_levenshtein_distance(#[case] a: &str, #[case] b: &str, #[case] expected: usize) {
+        assert_eq!(levenshtein_distance(a, b), expected);
+    }
+
+    #[rstest]
+    #[case("instal", vec!["install", "build", "lookup"], Some("install"))]
+    #[case("xyz", vec!["install", "build", "lookup"], None)]
+    fn test_suggest_closest(
+        #[case] name: &str,
+        #[case] candidates: Vec<&str>,
+        #[case] expected: Option<&str>,
+    ) {
+        assert_eq!(
+            suggest_closest(name, candidates.into_iter()),
+            expected.map(|s| s.to_string())
+        );
+    }
+
+    #[test]
+    fn test_expand_command_named_params() {
+        let params = vec!["src".to_string(), "dst".to_string()];
+        let arguments = vec!["a.txt".to_string(), "b.txt".to_string()];
+
+        let tokens = expand_command("cp {src} {dst}", &arguments, Some(&params), true).unwrap();
+
+        assert_eq!(tokens, vec!["cp", "a.txt", "b.txt"]);
+    }
+
+    #[test]
+    fn test_expand_command_named_and_positional_coexist() {
+        let params = vec!["src".to_string()];
+        let arguments = vec!["a.txt".to_string(), "-v".to_string()];
+
+        let tokens = expand_command("cp {src} {0}", &arguments, Some(&params), true).unwrap();
+
+        assert_eq!(tokens, vec!["cp", "a.txt", "a.txt", "-v"]);
+    }
+
+    #[test]
+    fn test_expand_command_unbound_named_param_errors() {
+        let params = vec!["src".to_string()];
+        let arguments = vec!["a.txt".to_string()];
+
+        let result = expand_command("cp {src} {dst}", &arguments, Some(&params), true);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_expand_command_excludes_leftovers_when_disabled() {
+        let arguments = vec!["a.txt".to_string(), "-v".to_string()];
+
+        let tokens = expand_command("cp {0}", &arguments, None, false).unwrap();
+
+        assert_eq!(tokens, vec!["cp", "a.txt"]);
+    }
+
+    #[test]
+    fn test_pipeline_leftover_argument_only_appended_to_first_stage() {
+        let args = vec!["testfile.txt".to_string()];
+        let expansions = HashMap::new();
+
+        let stages = ["cat", "grep foo"]
+            .iter()
+            .enumerate()
+            .map(|(i, item)| get_arguments(item, &args, &expansions, None, i == 0))
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+
+        assert_eq!(stages[0], vec!["cat", "testfile.txt"]);
+        assert_eq!(stages[1], vec!["grep", "foo"]);
+    }
+
+    #[test]
+    fn test_execute_pipeline_empty_stages_errors() {
+        let result = execute_pipeline(vec![], None, None);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_execute_pipeline_pipes_output_between_stages() {
+        let stages = vec![
+            vec!["echo".to_string(), "hello".to_string()],
+            vec!["wc".to_string(), "-c".to_string()],
+        ];
+
+        let status = execute_pipeline(stages, None, None).unwrap();
+
+        assert!(status.success());
+    }
+
+    #[test]
+    fn test_execute_pipeline_spawn_failure_errors_cleanly() {
+        let stages = vec![
+            vec!["echo".to_string(), "hello".to_string()],
+            vec!["definitely-not-a-real-epithet-test-binary".to_string()],
+        ];
+
+        let result = execute_pipeline(stages, None, None);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_expand_env_substitutes_positional_argument() {
+        let mut env = HashMap::new();
+        env.insert("TARGET_DIR".to_string(), "{0}".to_string());
+        let arguments = vec!["/srv/app".to_string()];
+
+        let expanded = expand_env(Some(&env), &arguments, &HashMap::new(), None)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(expanded.get("TARGET_DIR").unwrap(), "/srv/app");
+    }
+
+    #[test]
+    fn test_expand_env_expands_at_prefixed_argument() {
+        let mut env = HashMap::new();
+        env.insert("GREETING".to_string(), "{0}".to_string());
+        let mut expansions = HashMap::new();
+        expansions.insert("hello".to_string(), "Hello, world!".to_string());
+        let arguments = vec!["@hello".to_string()];
+
+        let expanded = expand_env(Some(&env), &arguments, &expansions, None)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(expanded.get("GREETING").unwrap(), "Hello, world!");
+    }
+
+    #[test]
+    fn test_expand_env_none_returns_none() {
+        let result = expand_env(None, &[], &HashMap::new(), None).unwrap();
+
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn test_apply_cwd_and_env_sets_working_directory() {
+        let mut command = Command::new("pwd");
+        apply_cwd_and_env(&mut command, Some("/tmp"), None);
+
+        let output = command.output().unwrap();
+
+        assert_eq!(String::from_utf8(output.stdout).unwrap().trim(), "/tmp");
+    }
+
+    #[test]
+    fn test_apply_cwd_and_env_sets_environment_variables() {
+        let mut env = HashMap::new();
+        env.insert("EPITHET_TEST_VAR".to_string(), "hello".to_string());
+
+        let mut command = Command::new("sh");
+        command.args(["-c", "echo $EPITHET_TEST_VAR"]);
+        apply_cwd_and_env(&mut command, None, Some(&env));
+
+        let output = command.output().unwrap();
+
+        assert_eq!(String::from_utf8(output.stdout).unwrap().trim(), "hello");
+    }
 }
@@ -1,11 +1,13 @@
 use anyhow::Result;
 use std::{
+    collections::HashMap,
     os::unix::fs,
     path::{Path, PathBuf},
 };
 
 use clap::Parser;
-use epithet::epithet_config::{get_config_path, EpithetConfig};
+use epithet::completions::{self, Shell};
+use epithet::epithet_config::{get_config_path, Alias, EpithetConfig, Execution, SubAlias};
 
 const BUILD_NAME: &str = env!("CARGO_PKG_NAME");
 const VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -28,6 +30,46 @@ enum Commands {
         alias: String,
         args: Vec<String>,
     },
+
+    Completions {
+        shell: Shell,
+    },
+
+    #[command(hide = true)]
+    Complete {
+        alias: String,
+        current: String,
+        position: usize,
+    },
+
+    Add {
+        name: String,
+
+        #[arg(long)]
+        command: Option<String>,
+
+        #[arg(long, num_args = 1..)]
+        and: Option<Vec<String>>,
+
+        #[arg(long, num_args = 1..)]
+        or: Option<Vec<String>>,
+
+        #[arg(long, num_args = 1..)]
+        pipeline: Option<Vec<String>>,
+
+        #[arg(long = "sub", value_name = "NAME=CMD")]
+        sub: Vec<String>,
+
+        #[arg(long)]
+        cwd: Option<String>,
+
+        #[arg(long = "env", value_name = "KEY=VALUE")]
+        env: Vec<String>,
+    },
+
+    Remove {
+        name: String,
+    },
 }
 
 fn main() {
@@ -56,17 +98,159 @@ fn epithet_command(cli: &Cli, config: &EpithetConfig) -> Result<()> {
             install_aliases(*force, config)?;
         }
         Commands::Lookup { alias, args } => {
-            if let Some(alias) = config.lookup_alias(alias, args) {
+            if let Some(alias) = config.lookup_alias(alias, args)? {
                 println!("{}", alias);
             } else {
                 println!("Alias not found: {}", alias);
             }
         }
+        Commands::Completions { shell } => {
+            print!("{}", completions::completion_script(*shell, config));
+        }
+        Commands::Complete {
+            alias,
+            current,
+            position,
+        } => {
+            for candidate in completions::complete(config, alias, current, *position) {
+                println!("{}", candidate);
+            }
+        }
+        Commands::Add {
+            name,
+            command,
+            and,
+            or,
+            pipeline,
+            sub,
+            cwd,
+            env,
+        } => {
+            add_alias(
+                config,
+                name,
+                command,
+                and,
+                or,
+                pipeline,
+                sub,
+                cwd,
+                env,
+                &get_config_path(),
+            )?;
+        }
+        Commands::Remove { name } => {
+            remove_alias(config, name, &get_config_path())?;
+        }
     }
 
     Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
+fn add_alias(
+    config: &EpithetConfig,
+    name: &str,
+    command: &Option<String>,
+    and: &Option<Vec<String>>,
+    or: &Option<Vec<String>>,
+    pipeline: &Option<Vec<String>>,
+    sub: &[String],
+    cwd: &Option<String>,
+    env: &[String],
+    path: &Path,
+) -> Result<()> {
+    let execution = command
+        .clone()
+        .map(Execution::Command)
+        .or_else(|| and.clone().map(Execution::And))
+        .or_else(|| or.clone().map(Execution::Or))
+        .or_else(|| pipeline.clone().map(Execution::Pipeline));
+
+    if execution.is_none() && sub.is_empty() {
+        anyhow::bail!(
+            "Must specify --command, --and, --or, --pipeline, or at least one --sub"
+        );
+    }
+
+    let sub_aliases = if sub.is_empty() {
+        None
+    } else {
+        Some(
+            sub.iter()
+                .map(|entry| parse_sub_alias(entry))
+                .collect::<Result<Vec<_>>>()?,
+        )
+    };
+
+    let env = if env.is_empty() {
+        None
+    } else {
+        Some(
+            env.iter()
+                .map(|entry| parse_env_entry(entry))
+                .collect::<Result<HashMap<_, _>>>()?,
+        )
+    };
+
+    let alias = Alias {
+        command: execution,
+        sub_aliases,
+        expansions: None,
+        cwd: cwd.clone(),
+        env,
+        params: None,
+    };
+
+    let mut config = config.clone();
+    config
+        .aliases
+        .get_or_insert_with(HashMap::new)
+        .insert(name.to_string(), alias);
+    config.write(path)?;
+
+    Ok(())
+}
+
+fn parse_sub_alias(entry: &str) -> Result<SubAlias> {
+    let (name, command) = entry
+        .split_once('=')
+        .ok_or_else(|| anyhow::anyhow!("Invalid --sub value '{}', expected NAME=CMD", entry))?;
+
+    Ok(SubAlias {
+        name: name.to_string(),
+        execution: Execution::Command(command.to_string()),
+        cwd: None,
+        env: None,
+        params: None,
+    })
+}
+
+fn parse_env_entry(entry: &str) -> Result<(String, String)> {
+    let (key, value) = entry
+        .split_once('=')
+        .ok_or_else(|| anyhow::anyhow!("Invalid --env value '{}', expected KEY=VALUE", entry))?;
+
+    Ok((key.to_string(), value.to_string()))
+}
+
+fn remove_alias(config: &EpithetConfig, name: &str, path: &Path) -> Result<()> {
+    let mut config = config.clone();
+    let removed = config
+        .aliases
+        .as_mut()
+        .map(|aliases| aliases.remove(name).is_some())
+        .unwrap_or(false);
+
+    if !removed {
+        anyhow::bail!("Alias not found: {}", name);
+    }
+
+    config.write(path)?;
+
+    Ok(())
+}
+
 fn alias_execution(command: &str, args: &[String], config: &EpithetConfig) -> Result<()> {
     config.execute(command, args)?;
     Ok(())
@@ -113,3 +297,120 @@ fn install_aliases(force: bool, config: &EpithetConfig) -> Result<()> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn empty_config() -> EpithetConfig {
+        EpithetConfig {
+            global_expansions: None,
+            aliases: None,
+        }
+    }
+
+    fn scratch_config_path(test_name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("epithet-main-test-{}", test_name));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir.join("epithet.toml")
+    }
+
+    #[test]
+    fn test_add_alias_rejects_when_no_execution_or_sub_given() {
+        let config = empty_config();
+        let path = scratch_config_path("reject-empty");
+
+        let result = add_alias(
+            &config, "greet", &None, &None, &None, &None, &[], &None, &[], &path,
+        );
+
+        assert!(result.is_err());
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn test_add_alias_writes_command_cwd_and_env() {
+        let config = empty_config();
+        let path = scratch_config_path("command-cwd-env");
+
+        add_alias(
+            &config,
+            "greet",
+            &Some("echo hi".to_string()),
+            &None,
+            &None,
+            &None,
+            &[],
+            &Some("/tmp".to_string()),
+            &["FOO=bar".to_string()],
+            &path,
+        )
+        .unwrap();
+
+        let read_back = EpithetConfig::read(&path).unwrap();
+        let alias = &read_back.aliases.unwrap()["greet"];
+        assert!(matches!(&alias.command, Some(Execution::Command(cmd)) if cmd == "echo hi"));
+        assert_eq!(alias.cwd.as_deref(), Some("/tmp"));
+        assert_eq!(alias.env.as_ref().unwrap()["FOO"], "bar");
+    }
+
+    #[test]
+    fn test_add_alias_accepts_sub_only() {
+        let config = empty_config();
+        let path = scratch_config_path("sub-only");
+
+        add_alias(
+            &config,
+            "greet",
+            &None,
+            &None,
+            &None,
+            &None,
+            &["loud=echo LOUD".to_string()],
+            &None,
+            &[],
+            &path,
+        )
+        .unwrap();
+
+        let read_back = EpithetConfig::read(&path).unwrap();
+        let alias = &read_back.aliases.unwrap()["greet"];
+        assert_eq!(alias.sub_aliases.as_ref().unwrap()[0].name, "loud");
+    }
+
+    #[test]
+    fn test_remove_alias_errors_when_alias_missing() {
+        let config = empty_config();
+        let path = scratch_config_path("remove-missing");
+
+        let result = remove_alias(&config, "missing", &path);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_remove_alias_removes_existing_alias() {
+        let config = empty_config();
+        let path = scratch_config_path("remove-existing");
+
+        add_alias(
+            &config,
+            "greet",
+            &Some("echo hi".to_string()),
+            &None,
+            &None,
+            &None,
+            &[],
+            &None,
+            &[],
+            &path,
+        )
+        .unwrap();
+
+        let added = EpithetConfig::read(&path).unwrap();
+        remove_alias(&added, "greet", &path).unwrap();
+
+        let read_back = EpithetConfig::read(&path).unwrap();
+        assert!(!read_back.aliases.unwrap().contains_key("greet"));
+    }
+}